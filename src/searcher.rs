@@ -28,59 +28,232 @@ impl Searcher {
     ///     collector (Collector): A collector that determines how the search
     ///         results will be collected. Only the TopDocs collector is
     ///         supported for now.
+    ///     facets (dict, optional): A mapping of field name to a list of
+    ///         facet root paths (e.g. `{"category": ["/category"]}`). For
+    ///         each root, the returned facet distribution contains the
+    ///         counts of its direct children, matching the breakdown that
+    ///         tantivy's `FacetCollector` computes.
+    ///     order_by_field (str, optional): The name of a u64/i64/f64 fast
+    ///         field to sort the top `nhits` results by, instead of the
+    ///         BM25 relevance score.
+    ///     descending (bool): When `order_by_field` is set, whether to sort
+    ///         in descending order. Defaults to `False`.
+    ///     offset (int): The number of leading results to skip, so that
+    ///         `items` covers `[offset, offset + nhits)`. Defaults to 0.
+    ///     facet_filters (dict, optional): A mapping of field name to a list
+    ///         of facet paths to restrict the search to (e.g.
+    ///         `{"category": ["/category/fiction"]}`). Paths for the same
+    ///         field are OR-ed together, and different fields are AND-ed.
+    ///     tweak_score_field (str, optional): The name of a u64 fast field
+    ///         whose value is combined into the BM25 score via
+    ///         `tweak_score_combine`, e.g. to boost by popularity.
+    ///     tweak_score_combine (str, optional): Either `"add"` (default) or
+    ///         `"multiply"`, controlling how `tweak_score_field`'s value is
+    ///         folded into the score.
+    ///     tweak_score_fn (callable, optional): A Python callable invoked
+    ///         per hit as `tweak_score_fn(score, fast_field_values)`, where
+    ///         `fast_field_values` is a dict keyed by `tweak_score_fields`.
+    ///         Its return value becomes the adjusted score. Takes
+    ///         precedence over `tweak_score_field` if both are given. Any
+    ///         exception it raises aborts the search with a ValueError.
+    ///     tweak_score_fields (list[str], optional): The u64 fast fields
+    ///         whose per-hit values are passed to `tweak_score_fn`.
     ///
-    /// Returns a list of tuples that contains the scores and DocAddress of the
-    /// search results.
+    /// Returns a dict with the overall `count`, the `items` (a list of
+    /// tuples containing the DocAddress of the search results, paired with
+    /// either the BM25 score, the value of `order_by_field`, or the
+    /// adjusted score when score tweaking is used) and the `facets`
+    /// distribution, a mapping of each requested root to a sorted list of
+    /// `(facet_string, count)` pairs.
     ///
-    /// Raises a ValueError if there was an error with the search.
-    #[args(size = 10)]
+    /// Raises a ValueError if there was an error with the search, or if
+    /// `order_by_field` or `tweak_score_field`/`tweak_score_fields` do not
+    /// name a fast field.
+    #[args(size = 10, descending = false, offset = 0)]
     fn search(
         &self,
         py: Python,
         query: &Query,
         nhits: usize,
-        facets: Option<&PyDict>
+        facets: Option<&PyDict>,
+        order_by_field: Option<&str>,
+        descending: bool,
+        offset: usize,
+        facet_filters: Option<&PyDict>,
+        tweak_score_field: Option<String>,
+        tweak_score_combine: Option<String>,
+        tweak_score_fn: Option<PyObject>,
+        tweak_score_fields: Option<Vec<String>>,
     ) -> PyResult<PyObject> {
 
-        let top_collector = tv::collector::TopDocs::with_limit(nhits);
+        if let Some(field_name) = order_by_field {
+            return self.search_order_by_field(
+                py, query, nhits, facets, field_name, descending, offset, facet_filters,
+            );
+        }
+
+        if tweak_score_field.is_some() || tweak_score_fn.is_some() {
+            return self.search_tweaked_score(
+                py,
+                query,
+                nhits,
+                facets,
+                offset,
+                facet_filters,
+                tweak_score_field,
+                tweak_score_combine,
+                tweak_score_fn,
+                tweak_score_fields,
+            );
+        }
+
+        let filtered_query = self.build_filtered_query(&query.inner, facet_filters)?;
+
+        // Older tantivy releases don't expose `TopDocs::and_offset`, so we
+        // over-fetch `offset + nhits` hits and skip the leading `offset`
+        // ourselves; the `Count` collector still sees the whole result set.
+        let top_collector = tv::collector::TopDocs::with_limit(offset + nhits);
 
         let mut facets_collector = tv::collector::MultiCollector::new();
+        let facet_handlers = self.build_facet_handlers(facets, &mut facets_collector)?;
 
-        let mut facets_requests = BTreeMap::new();
+        let ret = self.inner.search(&filtered_query, &(tv::collector::Count, top_collector, facets_collector));
 
-        // We create facets collector for each field and terms defined on the facets args
-        if let Some(facets_dict) = facets {
+        match ret {
+            Ok((count, top, mut facets_tv_results)) => {
+                let result = PyDict::new(py);
 
-            for key_value_any in facets_dict.items() {
-                if let Ok(key_value) = key_value_any.downcast_ref::<PyTuple>() {
-                    if key_value.len() != 2 {
-                        continue;
-                    }
-                    let key: String = key_value.get_item(0).extract()?;
-                    let field = self.schema.get_field(&key).ok_or_else(|| {
-                        exceptions::ValueError::py_err(format!(
-                            "Field `{}` is not defined in the schema.",
-                            key
-                        ))
-                    })?;
+                result.set_item("count", count)?;
 
-                    let mut facet_collector = tv::collector::FacetCollector::for_field(field);
+                let items: Vec<(f32, (u32, u32))> = top
+                    .iter()
+                    .skip(offset)
+                    .map(|(f, d)| (*f, (d.segment_ord(), d.doc())))
+                    .collect();
 
-                    if let Ok(value_list) = key_value.get_item(1).downcast_ref::<PyList>() {
-                        for value_element in value_list {
-                            if let Ok(s) = value_element.extract::<String>() {
-                                facet_collector.add_facet(&s);
-                            }
-                            
-                        }
-                        let facet_handler = facets_collector.add_collector(facet_collector);
-                        facets_requests.insert(key, facet_handler);
-                    }
-                }
+                result.set_item("items", items)?;
+
+                let facets_result = Searcher::extract_facet_results(facet_handlers, &mut facets_tv_results);
+                result.set_item("facets", facets_result)?;
+
+                Ok(result.into())
+
+            },
+            Err(e) => Err(exceptions::ValueError::py_err(e.to_string())),
+        }
+
+    }
+
+    /// `search` variant used when `order_by_field` is set: ranks the top
+    /// `nhits` results by a fast field instead of the BM25 score.
+    ///
+    /// Dispatches to a typed helper per fast field type, since u64/i64/f64
+    /// fast fields each need their own `fast_fields()` reader and we want
+    /// to hand back the field's real decoded value, not its raw encoded
+    /// sort key.
+    fn search_order_by_field(
+        &self,
+        py: Python,
+        query: &Query,
+        nhits: usize,
+        facets: Option<&PyDict>,
+        field_name: &str,
+        descending: bool,
+        offset: usize,
+        facet_filters: Option<&PyDict>,
+    ) -> PyResult<PyObject> {
+        let field = self.schema.get_field(field_name).ok_or_else(|| {
+            exceptions::ValueError::py_err(format!(
+                "Field `{}` is not defined in the schema.",
+                field_name
+            ))
+        })?;
+        let field_entry = self.schema.get_field_entry(field);
+        if !field_entry.is_fast() {
+            return Err(exceptions::ValueError::py_err(format!(
+                "Field `{}` is not a fast field.",
+                field_name
+            )));
+        }
+
+        let filtered_query = self.build_filtered_query(&query.inner, facet_filters)?;
+
+        match field_entry.field_type() {
+            tv::schema::FieldType::U64(_) => self.search_order_by_typed_field(
+                py, &filtered_query, nhits, offset, descending, facets,
+                move |segment_reader: &tv::SegmentReader| {
+                    segment_reader.fast_fields().u64(field).expect("checked to be a fast field above")
+                },
+            ),
+            tv::schema::FieldType::I64(_) => self.search_order_by_typed_field(
+                py, &filtered_query, nhits, offset, descending, facets,
+                move |segment_reader: &tv::SegmentReader| {
+                    segment_reader.fast_fields().i64(field).expect("checked to be a fast field above")
+                },
+            ),
+            tv::schema::FieldType::F64(_) => self.search_order_by_typed_field(
+                py, &filtered_query, nhits, offset, descending, facets,
+                move |segment_reader: &tv::SegmentReader| {
+                    segment_reader.fast_fields().f64(field).expect("checked to be a fast field above")
+                },
+            ),
+            _ => Err(exceptions::ValueError::py_err(format!(
+                "Field `{}` must be a u64, i64 or f64 fast field to order by.",
+                field_name
+            ))),
+        }
+    }
+
+    /// Ranks the top `offset + nhits` hits by a typed fast field, keyed so
+    /// that ascending requests keep the *smallest* values (not the largest
+    /// values reversed) and descending requests keep the largest, via
+    /// `TopDocs::tweak_score` with a direction-aware comparator.
+    fn search_order_by_typed_field<T>(
+        &self,
+        py: Python,
+        filtered_query: &dyn tv::query::Query,
+        nhits: usize,
+        offset: usize,
+        descending: bool,
+        facets: Option<&PyDict>,
+        get_reader: impl Fn(&tv::SegmentReader) -> tv::fastfield::FastFieldReader<T> + Send + Sync + 'static,
+    ) -> PyResult<PyObject>
+    where
+        T: PartialOrd + Clone + Send + Sync + IntoPy<PyObject> + 'static,
+    {
+        #[derive(Clone)]
+        struct OrderKey<T> {
+            value: T,
+            descending: bool,
+        }
+
+        impl<T: PartialEq> PartialEq for OrderKey<T> {
+            fn eq(&self, other: &Self) -> bool {
+                self.value == other.value
             }
         }
 
-        let ret = self.inner.search(&query.inner, &(tv::collector::Count, top_collector, facets_collector));
+        impl<T: PartialOrd> PartialOrd for OrderKey<T> {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                let ord = self.value.partial_cmp(&other.value)?;
+                Some(if self.descending { ord } else { ord.reverse() })
+            }
+        }
+
+        let order_collector = tv::collector::TopDocs::with_limit(offset + nhits).tweak_score(
+            move |segment_reader: &tv::SegmentReader| {
+                let reader = get_reader(segment_reader);
+                move |doc: tv::DocId, _original_score: tv::Score| OrderKey {
+                    value: reader.get(doc),
+                    descending,
+                }
+            },
+        );
+
+        let mut facets_collector = tv::collector::MultiCollector::new();
+        let facet_handlers = self.build_facet_handlers(facets, &mut facets_collector)?;
+
+        let ret = self.inner.search(filtered_query, &(tv::collector::Count, order_collector, facets_collector));
 
         match ret {
             Ok((count, top, mut facets_tv_results)) => {
@@ -88,50 +261,294 @@ impl Searcher {
 
                 result.set_item("count", count)?;
 
-                let items: Vec<(f32, (u32, u32))> =
-                    top.iter().map(|(f, d)| (*f, (d.segment_ord(), d.doc()))).collect();
+                let items: Vec<(T, (u32, u32))> = top
+                    .into_iter()
+                    .skip(offset)
+                    .map(|(key, d)| (key.value, (d.segment_ord(), d.doc())))
+                    .collect();
 
                 result.set_item("items", items)?;
 
-                let mut facets_result: BTreeMap<String, Vec<(String, u64)>> =
-                    BTreeMap::new();
-
-                // Go though all collectors that are registered
-                for (key, facet_collector) in facets_requests {
-                    let facet_count = facet_collector.extract(&mut facets_tv_results);
-                    let mut facet_vec = Vec::new();
-                    if let Some(facets_dict) = facets {
-                        match facets_dict.get_item(key.clone()) {
-                            Some(facets_list_by_key) => {
-                                if let Ok(facets_list_by_key_native) = facets_list_by_key.downcast_ref::<PyList>() {
-                                    for facet_value in facets_list_by_key_native {
-                                        if let Ok(s) = facet_value.extract::<String>() {
-                                            let facet_value_vec: Vec<(&tv::schema::Facet, u64)> = facet_count
-                                                .get(&s)
-                                                .collect();
-
-                                            // Go for all elements on facet and count to add on vector
-                                            for (facet_value_vec_element, facet_count) in facet_value_vec {
-                                                facet_vec.push((facet_value_vec_element.to_string(), facet_count))
-                                            }
-                                        }
-                                    }
+                let facets_result = Searcher::extract_facet_results(facet_handlers, &mut facets_tv_results);
+                result.set_item("facets", facets_result)?;
+
+                Ok(result.into())
+            },
+            Err(e) => Err(exceptions::ValueError::py_err(e.to_string())),
+        }
+    }
+
+    /// `search` variant used when a score-tweaking mode is requested:
+    /// reranks the top `nhits` results by blending a fast field into the
+    /// BM25 score, or by delegating to a Python callback, via
+    /// `TopDocs::tweak_score`.
+    fn search_tweaked_score(
+        &self,
+        py: Python,
+        query: &Query,
+        nhits: usize,
+        facets: Option<&PyDict>,
+        offset: usize,
+        facet_filters: Option<&PyDict>,
+        tweak_score_field: Option<String>,
+        tweak_score_combine: Option<String>,
+        tweak_score_fn: Option<PyObject>,
+        tweak_score_fields: Option<Vec<String>>,
+    ) -> PyResult<PyObject> {
+        // `tweak_score` reads these through a `u64` fast-field reader, so
+        // only u64 fast fields are accepted here; an i64/f64 fast field
+        // would otherwise make `.u64()` panic inside the scorer.
+        let require_u64_fast_field = |name: &str| -> PyResult<tv::schema::Field> {
+            let field = self.schema.get_field(name).ok_or_else(|| {
+                exceptions::ValueError::py_err(format!(
+                    "Field `{}` is not defined in the schema.",
+                    name
+                ))
+            })?;
+            let field_entry = self.schema.get_field_entry(field);
+            if !field_entry.is_fast() {
+                return Err(exceptions::ValueError::py_err(format!(
+                    "Field `{}` is not a fast field.",
+                    name
+                )));
+            }
+            if !matches!(field_entry.field_type(), tv::schema::FieldType::U64(_)) {
+                return Err(exceptions::ValueError::py_err(format!(
+                    "Field `{}` must be a u64 fast field to use for score tweaking.",
+                    name
+                )));
+            }
+            Ok(field)
+        };
+
+        let combine_field = tweak_score_field
+            .map(|name| require_u64_fast_field(&name))
+            .transpose()?;
+
+        let callback_fields: Vec<(String, tv::schema::Field)> = tweak_score_fields
+            .unwrap_or_default()
+            .into_iter()
+            .map(|name| {
+                let field = require_u64_fast_field(&name)?;
+                Ok((name, field))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let multiply = tweak_score_combine.as_deref() == Some("multiply");
+        let callback = tweak_score_fn;
+
+        // The per-doc scorer below can't return a `PyResult`, so the first
+        // error raised by `callback` is stashed here and surfaced after the
+        // search completes, instead of being silently swallowed.
+        let callback_error: std::sync::Arc<std::sync::Mutex<Option<String>>> =
+            std::sync::Arc::new(std::sync::Mutex::new(None));
+
+        let filtered_query = self.build_filtered_query(&query.inner, facet_filters)?;
+
+        let top_collector = tv::collector::TopDocs::with_limit(offset + nhits).tweak_score(
+            move |segment_reader: &tv::SegmentReader| {
+                let combine_reader = combine_field.map(|field| {
+                    segment_reader
+                        .fast_fields()
+                        .u64(field)
+                        .expect("checked to be a u64 fast field above")
+                });
+                let callback_readers: Vec<(String, tv::fastfield::FastFieldReader<u64>)> =
+                    callback_fields
+                        .iter()
+                        .map(|(name, field)| {
+                            let reader = segment_reader
+                                .fast_fields()
+                                .u64(*field)
+                                .expect("checked to be a u64 fast field above");
+                            (name.clone(), reader)
+                        })
+                        .collect();
+                let callback = callback.clone();
+                let callback_error = callback_error.clone();
+
+                move |doc: tv::DocId, original_score: tv::Score| -> tv::Score {
+                    // `tweak_score_fn` takes precedence over `tweak_score_field`
+                    // when both are given, per the documented contract above.
+                    if let Some(callback) = &callback {
+                        let values: BTreeMap<String, u64> = callback_readers
+                            .iter()
+                            .map(|(name, reader)| (name.clone(), reader.get(doc)))
+                            .collect();
+                        let adjusted: PyResult<f32> = Python::with_gil(|py| {
+                            callback.call1(py, (original_score, values))?.extract(py)
+                        });
+                        return match adjusted {
+                            Ok(score) => score,
+                            Err(e) => {
+                                let mut guard = callback_error.lock().unwrap();
+                                if guard.is_none() {
+                                    *guard = Some(e.to_string());
                                 }
+                                original_score
                             }
-                            None => println!("Not found.")
-                        }
+                        };
+                    }
+
+                    if let Some(reader) = &combine_reader {
+                        let value = reader.get(doc) as f32;
+                        return if multiply {
+                            original_score * value
+                        } else {
+                            original_score + value
+                        };
                     }
-                    facets_result.insert(key.clone(), facet_vec);
+
+                    original_score
                 }
+            },
+        );
+
+        let mut facets_collector = tv::collector::MultiCollector::new();
+        let facet_handlers = self.build_facet_handlers(facets, &mut facets_collector)?;
+
+        let ret = self.inner.search(&filtered_query, &(tv::collector::Count, top_collector, facets_collector));
+
+        if let Some(message) = callback_error.lock().unwrap().take() {
+            return Err(exceptions::ValueError::py_err(format!(
+                "tweak_score_fn raised an error: {}",
+                message
+            )));
+        }
 
+        match ret {
+            Ok((count, top, mut facets_tv_results)) => {
+                let result = PyDict::new(py);
+
+                result.set_item("count", count)?;
+
+                let items: Vec<(f32, (u32, u32))> = top
+                    .iter()
+                    .skip(offset)
+                    .map(|(f, d)| (*f, (d.segment_ord(), d.doc())))
+                    .collect();
+
+                result.set_item("items", items)?;
+
+                let facets_result = Searcher::extract_facet_results(facet_handlers, &mut facets_tv_results);
                 result.set_item("facets", facets_result)?;
 
                 Ok(result.into())
-
             },
             Err(e) => Err(exceptions::ValueError::py_err(e.to_string())),
         }
+    }
+
+    /// Combines the user's query with the requested facet filters: paths
+    /// under the same field are OR-ed together (a `BooleanQuery` of
+    /// `Should` `TermQuery`s), and different fields are AND-ed with each
+    /// other and with the original query.
+    fn build_filtered_query(
+        &self,
+        base_query: &dyn tv::query::Query,
+        facet_filters: Option<&PyDict>,
+    ) -> PyResult<Box<dyn tv::query::Query>> {
+        let facet_filters = match facet_filters {
+            Some(dict) if !dict.is_empty() => dict,
+            _ => return Ok(base_query.box_clone()),
+        };
+
+        let mut clauses: Vec<(tv::query::Occur, Box<dyn tv::query::Query>)> =
+            vec![(tv::query::Occur::Must, base_query.box_clone())];
+
+        for key_value_any in facet_filters.items() {
+            if let Ok(key_value) = key_value_any.downcast_ref::<PyTuple>() {
+                if key_value.len() != 2 {
+                    continue;
+                }
+                let key: String = key_value.get_item(0).extract()?;
+                let field = self.schema.get_field(&key).ok_or_else(|| {
+                    exceptions::ValueError::py_err(format!(
+                        "Field `{}` is not defined in the schema.",
+                        key
+                    ))
+                })?;
+
+                if let Ok(paths) = key_value.get_item(1).downcast_ref::<PyList>() {
+                    let mut term_clauses: Vec<(tv::query::Occur, Box<dyn tv::query::Query>)> =
+                        Vec::new();
+                    for path_any in paths {
+                        let path: String = path_any.extract()?;
+                        let facet = tv::schema::Facet::from(path.as_str());
+                        let term = tv::Term::from_facet(field, &facet);
+                        let term_query =
+                            tv::query::TermQuery::new(term, tv::schema::IndexRecordOption::Basic);
+                        term_clauses.push((tv::query::Occur::Should, Box::new(term_query)));
+                    }
+                    if !term_clauses.is_empty() {
+                        let field_query = tv::query::BooleanQuery::from(term_clauses);
+                        clauses.push((tv::query::Occur::Must, Box::new(field_query)));
+                    }
+                }
+            }
+        }
 
+        Ok(Box::new(tv::query::BooleanQuery::from(clauses)))
+    }
+
+    /// Builds one `FacetCollector` per requested facet root (keyed by the
+    /// root itself) and registers them on `facets_collector`.
+    fn build_facet_handlers(
+        &self,
+        facets: Option<&PyDict>,
+        facets_collector: &mut tv::collector::MultiCollector,
+    ) -> PyResult<Vec<(String, tv::collector::FruitHandle<tv::collector::FacetCounts>)>> {
+        let mut facet_handlers = Vec::new();
+
+        if let Some(facets_dict) = facets {
+            for key_value_any in facets_dict.items() {
+                if let Ok(key_value) = key_value_any.downcast_ref::<PyTuple>() {
+                    if key_value.len() != 2 {
+                        continue;
+                    }
+                    let key: String = key_value.get_item(0).extract()?;
+                    let field = self.schema.get_field(&key).ok_or_else(|| {
+                        exceptions::ValueError::py_err(format!(
+                            "Field `{}` is not defined in the schema.",
+                            key
+                        ))
+                    })?;
+
+                    if let Ok(roots) = key_value.get_item(1).downcast_ref::<PyList>() {
+                        for root_any in roots {
+                            let root: String = root_any.extract()?;
+                            let mut facet_collector = tv::collector::FacetCollector::for_field(field);
+                            facet_collector.add_facet(&root);
+                            let facet_handler = facets_collector.add_collector(facet_collector);
+                            facet_handlers.push((root, facet_handler));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(facet_handlers)
+    }
+
+    /// Extracts the `(facet_string, count)` breakdown for each registered
+    /// facet root out of the `MultiCollector`'s combined fruit.
+    fn extract_facet_results(
+        facet_handlers: Vec<(String, tv::collector::FruitHandle<tv::collector::FacetCounts>)>,
+        facets_tv_results: &mut tv::collector::MultiFruit,
+    ) -> BTreeMap<String, Vec<(String, u64)>> {
+        let mut facets_result: BTreeMap<String, Vec<(String, u64)>> = BTreeMap::new();
+
+        for (root, facet_handler) in facet_handlers {
+            let facet_count = facet_handler.extract(facets_tv_results);
+            let mut facet_vec: Vec<(String, u64)> = facet_count
+                .get(&root)
+                .map(|(facet, count)| (facet.to_string(), count))
+                .collect();
+            facet_vec.sort();
+            facets_result.insert(root, facet_vec);
+        }
+
+        facets_result
     }
 
     /// Returns the overall number of documents in the index.
@@ -166,6 +583,100 @@ impl Searcher {
         })
     }
 
+    /// Searches for facet values under `field` whose path starts with
+    /// `prefix`, for type-ahead facet selection over large hierarchies.
+    ///
+    /// Args:
+    ///     field (str): The name of the facet field to search.
+    ///     prefix (str): The facet path prefix to match against, e.g.
+    ///         `"/category/bio"`.
+    ///     top_k (int): The maximum number of facet values to return.
+    ///
+    /// Walks each segment's facet `TermDictionary` directly rather than
+    /// going through a `FacetCollector`, since we only want the matching
+    /// values, not a full distribution over a fixed set of roots. Facet
+    /// ordinals are segment-local, so counts are aggregated by the decoded
+    /// facet string, not the ordinal.
+    ///
+    /// For each segment, the matching ordinals are collected from the term
+    /// dictionary first, then every document is scanned exactly once via
+    /// the multivalued fast-field reader, counting only the ordinals that
+    /// matched. This avoids rescanning the whole segment per matching term.
+    ///
+    /// Returns a list of `(facet_string, count)` pairs sorted by count
+    /// descending. Raises a ValueError if the field isn't in the schema.
+    fn facet_search(
+        &self,
+        field: String,
+        prefix: String,
+        top_k: usize,
+    ) -> PyResult<Vec<(String, u64)>> {
+        let field = self.schema.get_field(&field).ok_or_else(|| {
+            exceptions::ValueError::py_err(format!(
+                "Field `{}` is not defined in the schema.",
+                field
+            ))
+        })?;
+
+        // The term dictionary stores the facet-encoded representation
+        // (components joined by `\u{0}`, no leading `/`), so the prefix
+        // must be encoded the same way before it can be compared against
+        // term bytes.
+        let encoded_prefix = tv::schema::Facet::from(prefix.as_str())
+            .encoded_str()
+            .as_bytes()
+            .to_vec();
+
+        let mut counts: BTreeMap<String, u64> = BTreeMap::new();
+
+        for segment_reader in self.inner.segment_readers() {
+            let facet_reader = segment_reader.facet_reader(field).map_err(to_pyerr)?;
+            let term_dict = facet_reader.facet_dict();
+
+            let mut facet_stream = term_dict
+                .range()
+                .ge(encoded_prefix.as_slice())
+                .into_stream()
+                .map_err(to_pyerr)?;
+
+            let mut matching_ords: BTreeMap<u64, String> = BTreeMap::new();
+            while facet_stream.advance() {
+                let term_bytes = facet_stream.key();
+                if !term_bytes.starts_with(encoded_prefix.as_slice()) {
+                    break;
+                }
+
+                let facet = tv::schema::Facet::from_encoded(term_bytes.to_vec()).map_err(to_pyerr)?;
+                matching_ords.insert(facet_stream.term_ord(), facet.to_string());
+            }
+
+            if matching_ords.is_empty() {
+                continue;
+            }
+
+            let mut ords = Vec::new();
+            for doc in 0..segment_reader.max_doc() {
+                if segment_reader
+                    .delete_bitset()
+                    .map_or(false, |bitset| bitset.is_deleted(doc))
+                {
+                    continue;
+                }
+                facet_reader.facet_ords(doc, &mut ords);
+                for ord in &ords {
+                    if let Some(facet_str) = matching_ords.get(ord) {
+                        *counts.entry(facet_str.clone()).or_insert(0) += 1;
+                    }
+                }
+            }
+        }
+
+        let mut results: Vec<(String, u64)> = counts.into_iter().collect();
+        results.sort_by(|a, b| b.1.cmp(&a.1));
+        results.truncate(top_k);
+        Ok(results)
+    }
+
 }
 
 